@@ -0,0 +1,211 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{parse_dictionary, Correctness, Guess, Guesser};
+
+const DICTIONARY: &str = include_str!("../../dictionary.txt");
+
+/// A cheaply-queryable distillation of a guess history, so a guesser can prune the
+/// dictionary down to still-possible answers by checking each candidate against it,
+/// instead of recomputing [`Correctness`] for every candidate against every past guess.
+/// It is built with [`Constraints::from_history`], which scans the whole history once.
+#[derive(Debug, Clone)]
+pub struct Constraints<const N: usize = 5> {
+    /// Letters pinned to a position by a `Correct` mask.
+    fixed: [Option<char>; N],
+    /// Per-position letters ruled out there by a `Misplaced` mask.
+    banned: [HashSet<char>; N],
+    /// Minimum number of occurrences a letter must still have, from `Correct` +
+    /// `Misplaced` counts in any one guess.
+    min_count: HashMap<char, usize>,
+    /// Letters that appeared only as `Wrong` in some guess, so can't appear at all.
+    excluded: HashSet<char>,
+}
+
+impl<const N: usize> Default for Constraints<N> {
+    fn default() -> Self {
+        Self {
+            fixed: [None; N],
+            banned: std::array::from_fn(|_| HashSet::new()),
+            min_count: HashMap::new(),
+            excluded: HashSet::new(),
+        }
+    }
+}
+
+impl<const N: usize> Constraints<N> {
+    pub fn from_history(history: &[Guess<N>]) -> Self {
+        let mut constraints = Self::default();
+        for guess in history {
+            constraints.absorb(guess);
+        }
+        constraints
+    }
+
+    fn absorb(&mut self, guess: &Guess<N>) {
+        let chars: Vec<char> = guess.word.chars().collect();
+        let mut counts: HashMap<char, usize> = HashMap::new();
+
+        for (i, (&c, mask)) in chars.iter().zip(guess.mask.iter()).enumerate() {
+            match mask {
+                Correctness::Correct => {
+                    self.fixed[i] = Some(c);
+                    *counts.entry(c).or_insert(0) += 1;
+                }
+                Correctness::Misplaced => {
+                    self.banned[i].insert(c);
+                    *counts.entry(c).or_insert(0) += 1;
+                }
+                Correctness::Wrong => {}
+            }
+        }
+
+        for (&c, &count) in &counts {
+            let min = self.min_count.entry(c).or_insert(0);
+            *min = (*min).max(count);
+        }
+
+        // A letter that only ever showed up `Wrong` in this guess (and was never
+        // `Correct`/`Misplaced` elsewhere in it) can't be in the answer at all. Repeated
+        // letters need this done per-guess: `counts` already excludes such letters.
+        for &c in &chars {
+            if !counts.contains_key(&c) {
+                self.excluded.insert(c);
+            }
+        }
+    }
+
+    /// Does `word` satisfy every constraint gathered so far?
+    pub fn matches(&self, word: &str) -> bool {
+        let chars: Vec<char> = word.chars().collect();
+        if chars.len() != N {
+            return false;
+        }
+
+        for (i, &c) in chars.iter().enumerate() {
+            if self.fixed[i].is_some_and(|fixed| fixed != c) {
+                return false;
+            }
+            if self.banned[i].contains(&c) {
+                return false;
+            }
+        }
+
+        if chars.iter().any(|c| self.excluded.contains(c)) {
+            return false;
+        }
+
+        self.min_count
+            .iter()
+            .all(|(&c, &min)| chars.iter().filter(|&&ch| ch == c).count() >= min)
+    }
+}
+
+/// A guesser that narrows the dictionary down using [`Constraints`] each round instead
+/// of rescanning the raw history and recomputing [`Correctness`] against every
+/// candidate word.
+///
+/// Generic over `N` like [`Wordle`](crate::Wordle), but it always loads the bundled
+/// 5-letter `dictionary.txt`, so in practice it can only play `N = 5` games until a
+/// dictionary for another word length is bundled alongside it.
+pub struct ConstraintGuesser<const N: usize = 5> {
+    remaining: HashMap<&'static str, usize>,
+}
+
+impl<const N: usize> ConstraintGuesser<N> {
+    pub fn new() -> Self {
+        Self {
+            remaining: parse_dictionary(DICTIONARY),
+        }
+    }
+}
+
+impl<const N: usize> Default for ConstraintGuesser<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Guesser<N> for ConstraintGuesser<N> {
+    fn guess(&mut self, history: &[Guess<N>]) -> String {
+        let constraints = Constraints::from_history(history);
+        self.remaining.retain(|word, _| constraints.matches(word));
+
+        self.remaining
+            .iter()
+            .max_by_key(|&(_, &count)| count)
+            .map(|(&word, _)| word.to_string())
+            .unwrap_or_else(|| "tarse".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn guess(word: &str, mask: [Correctness; 5]) -> Guess {
+        Guess {
+            word: word.to_string(),
+            mask,
+        }
+    }
+
+    // "aabbb" guessed as "ccaac" -> W W M M W (see `repeat_yellow` in `crate::tests`):
+    // the repeated 'a' is misplaced twice, and 'c' never matches at all.
+    fn repeat_yellow_history() -> Vec<Guess> {
+        vec![guess(
+            "ccaac",
+            [
+                Correctness::Wrong,
+                Correctness::Wrong,
+                Correctness::Misplaced,
+                Correctness::Misplaced,
+                Correctness::Wrong,
+            ],
+        )]
+    }
+
+    #[test]
+    fn matches_the_answer_that_produced_the_history() {
+        let constraints = Constraints::from_history(&repeat_yellow_history());
+        assert!(constraints.matches("aabbb"));
+    }
+
+    #[test]
+    fn excludes_a_letter_that_was_never_correct_or_misplaced() {
+        let constraints = Constraints::from_history(&repeat_yellow_history());
+        // every 'c' in the guess was Wrong, so no remaining candidate may contain one.
+        assert!(!constraints.matches("ccaac"));
+    }
+
+    #[test]
+    fn rejects_a_letter_repeated_at_a_banned_position() {
+        let constraints = Constraints::from_history(&repeat_yellow_history());
+        // position 2 banned 'a' (it was Misplaced there), so an 'a' there is still wrong.
+        assert!(!constraints.matches("aaabb"));
+    }
+
+    #[test]
+    fn enforces_the_minimum_repeat_count() {
+        let constraints = Constraints::from_history(&repeat_yellow_history());
+        // only one 'a', but the guess's two Misplaced/Correct 'a's require at least two.
+        assert!(!constraints.matches("aebbb"));
+    }
+
+    #[test]
+    fn a_correct_letter_pins_its_position() {
+        let history = vec![guess(
+            "abcde",
+            [
+                Correctness::Correct,
+                Correctness::Wrong,
+                Correctness::Wrong,
+                Correctness::Wrong,
+                Correctness::Wrong,
+            ],
+        )];
+        let constraints = Constraints::from_history(&history);
+
+        assert!(constraints.matches("azzzz"));
+        assert!(!constraints.matches("zazzz"));
+    }
+}
@@ -0,0 +1,38 @@
+use std::collections::HashMap;
+
+use crate::algorithms::constraints::Constraints;
+use crate::{Guess, Guesser};
+
+/// A guesser that prunes candidates with [`Constraints`] and, among what's left, biases
+/// its pick toward the most frequent real word - the standard trick for breaking ties
+/// between equally-informative guesses. Unlike [`Niave`](super::niave::Niave) and
+/// [`ConstraintGuesser`](super::constraints::ConstraintGuesser), which each keep their
+/// own copy of the dictionary, this borrows the frequency table straight off the
+/// [`Wordle`](crate::Wordle) instance that's playing the game, so it's generic over `N`
+/// purely by following whatever dictionary that `Wordle<N>` was built with.
+pub struct Frequency<'a, const N: usize = 5> {
+    frequencies: &'a HashMap<&'static str, usize>,
+    constraints: Constraints<N>,
+}
+
+impl<'a, const N: usize> Frequency<'a, N> {
+    pub fn new(frequencies: &'a HashMap<&'static str, usize>) -> Self {
+        Self {
+            frequencies,
+            constraints: Constraints::default(),
+        }
+    }
+}
+
+impl<'a, const N: usize> Guesser<N> for Frequency<'a, N> {
+    fn guess(&mut self, history: &[Guess<N>]) -> String {
+        self.constraints = Constraints::from_history(history);
+
+        self.frequencies
+            .iter()
+            .filter(|(word, _)| self.constraints.matches(word))
+            .max_by_key(|&(_, &count)| count)
+            .map(|(&word, _)| word.to_string())
+            .unwrap_or_else(|| "tarse".to_string())
+    }
+}
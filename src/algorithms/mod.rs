@@ -0,0 +1,3 @@
+pub mod constraints;
+pub mod frequency;
+pub mod niave;
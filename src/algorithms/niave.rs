@@ -0,0 +1,46 @@
+use std::collections::HashMap;
+
+use crate::{parse_dictionary, Correctness, Guess, Guesser};
+
+const DICTIONARY: &str = include_str!("../../dictionary.txt");
+
+/// The simplest possible guesser: keep every dictionary word that is still consistent
+/// with the guesses so far, and guess the most frequent one that remains. Each call
+/// filters `remaining` by the most recent guess only, so one instance has to see every
+/// round in order for its candidates to reflect the whole history.
+///
+/// Generic over `N` like [`Wordle`](crate::Wordle), but it always loads the bundled
+/// 5-letter `dictionary.txt`, so in practice it can only play `N = 5` games until a
+/// dictionary for another word length is bundled alongside it.
+pub struct Niave<const N: usize = 5> {
+    remaining: HashMap<&'static str, usize>,
+}
+
+impl<const N: usize> Niave<N> {
+    pub fn new() -> Self {
+        Self {
+            remaining: parse_dictionary(DICTIONARY),
+        }
+    }
+}
+
+impl<const N: usize> Default for Niave<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Guesser<N> for Niave<N> {
+    fn guess(&mut self, history: &[Guess<N>]) -> String {
+        if let Some(last) = history.last() {
+            self.remaining
+                .retain(|word, _| Correctness::compute(word, &last.word) == last.mask);
+        }
+
+        self.remaining
+            .iter()
+            .max_by_key(|&(_, &count)| count)
+            .map(|(&word, _)| word.to_string())
+            .unwrap_or_else(|| "tarse".to_string())
+    }
+}
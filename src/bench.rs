@@ -0,0 +1,147 @@
+use std::fmt;
+
+use rayon::prelude::*;
+
+use crate::{Guesser, Wordle, MAX_GUESSES};
+
+/// Aggregated outcome of running a guesser over a whole set of answers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BenchReport {
+    pub total: usize,
+    pub wins: usize,
+    pub losses: usize,
+    /// `histogram[i]` is the number of wins that took `i + 1` guesses.
+    pub histogram: [usize; MAX_GUESSES],
+}
+
+impl BenchReport {
+    fn merge(mut self, other: Self) -> Self {
+        self.total += other.total;
+        self.wins += other.wins;
+        self.losses += other.losses;
+        for i in 0..MAX_GUESSES {
+            self.histogram[i] += other.histogram[i];
+        }
+        self
+    }
+
+    /// Average number of guesses among games that were won.
+    pub fn average_guesses(&self) -> f64 {
+        if self.wins == 0 {
+            return 0.0;
+        }
+        let total_guesses: usize = self
+            .histogram
+            .iter()
+            .enumerate()
+            .map(|(i, &count)| (i + 1) * count)
+            .sum();
+        total_guesses as f64 / self.wins as f64
+    }
+}
+
+impl Default for BenchReport {
+    fn default() -> Self {
+        Self {
+            total: 0,
+            wins: 0,
+            losses: 0,
+            histogram: [0; MAX_GUESSES],
+        }
+    }
+}
+
+impl fmt::Display for BenchReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "played {} games: {} wins, {} losses",
+            self.total, self.wins, self.losses
+        )?;
+        writeln!(f, "average guesses (wins only): {:.3}", self.average_guesses())?;
+        for (i, count) in self.histogram.iter().enumerate() {
+            if *count > 0 {
+                writeln!(f, "{:>2}: {}", i + 1, "*".repeat(*count))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<const N: usize> Wordle<N> {
+    /// Run `factory` over every answer in `answers`, parallelizing across answers with
+    /// rayon, and aggregate the outcomes into a [`BenchReport`].
+    pub fn benchmark<G, F>(&self, answers: &[&'static str], factory: F) -> BenchReport
+    where
+        G: Guesser<N>,
+        F: Fn() -> G + Sync,
+    {
+        const CHUNK_SIZE: usize = 64;
+        answers
+            .par_chunks(CHUNK_SIZE)
+            .map(|chunk| {
+                let mut report = BenchReport::default();
+                for &answer in chunk {
+                    let guesser = factory();
+                    report.total += 1;
+                    match self.play(answer, guesser) {
+                        Some(guesses) => {
+                            report.wins += 1;
+                            report.histogram[guesses - 1] += 1;
+                        }
+                        None => report.losses += 1,
+                    }
+                }
+                report
+            })
+            .reduce(BenchReport::default, BenchReport::merge)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(total: usize, wins: usize, losses: usize, histogram: [usize; MAX_GUESSES]) -> BenchReport {
+        BenchReport {
+            total,
+            wins,
+            losses,
+            histogram,
+        }
+    }
+
+    #[test]
+    fn merge_sums_every_field() {
+        let mut a = [0; MAX_GUESSES];
+        a[0] = 1;
+        let mut b = [0; MAX_GUESSES];
+        b[0] = 2;
+        b[1] = 3;
+
+        let merged = report(1, 1, 0, a).merge(report(4, 3, 1, b));
+
+        assert_eq!(merged.total, 5);
+        assert_eq!(merged.wins, 4);
+        assert_eq!(merged.losses, 1);
+        assert_eq!(merged.histogram[0], 3);
+        assert_eq!(merged.histogram[1], 3);
+    }
+
+    #[test]
+    fn average_guesses_weights_by_histogram_bucket() {
+        let mut histogram = [0; MAX_GUESSES];
+        histogram[0] = 1; // one win in 1 guess
+        histogram[2] = 1; // one win in 3 guesses
+        let report = report(2, 2, 0, histogram);
+
+        assert_eq!(report.average_guesses(), 2.0);
+    }
+
+    #[test]
+    fn average_guesses_is_zero_with_no_wins() {
+        let report = report(3, 0, 3, [0; MAX_GUESSES]);
+
+        assert_eq!(report.average_guesses(), 0.0);
+    }
+}
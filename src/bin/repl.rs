@@ -0,0 +1,67 @@
+//! Interactive solver/hint REPL. Gated behind the `cli` feature via this bin target's
+//! `required-features` in Cargo.toml: `cargo run --features cli --bin repl`.
+
+use std::io::{self, Write};
+
+use za_wardle::cli::{Algorithm, Repl};
+
+fn main() {
+    let mut repl = Repl::new();
+    println!("za-wardle REPL. commands: new [word] | guess <word> | undo [n] | algo <niave|constraints|frequency> | hint | quit");
+
+    let stdin = io::stdin();
+    loop {
+        print!("> ");
+        io::stdout().flush().ok();
+
+        let mut line = String::new();
+        if stdin.read_line(&mut line).unwrap_or(0) == 0 {
+            break;
+        }
+        let mut parts = line.trim().split_whitespace();
+
+        match parts.next() {
+            Some("new") => match repl.new_game(parts.next()) {
+                Ok(()) => println!("started a new game"),
+                Err(e) => println!("error: {e}"),
+            },
+            Some("guess") => {
+                let Some(word) = parts.next() else {
+                    println!("usage: guess <word>");
+                    continue;
+                };
+                match repl.guess(word) {
+                    Ok(guess) => {
+                        println!("{guess}");
+                        if repl.won() {
+                            println!("you won in {} guesses!", repl.history().len());
+                        }
+                    }
+                    Err(e) => println!("error: {e}"),
+                }
+            }
+            Some("undo") => {
+                let n = parts.next().and_then(|n| n.parse().ok()).unwrap_or(1);
+                repl.undo(n);
+                println!("undid {n} guess(es)");
+            }
+            Some("algo") => {
+                let Some(name) = parts.next() else {
+                    println!("usage: algo <niave|constraints|frequency>");
+                    continue;
+                };
+                match Algorithm::parse(name) {
+                    Some(algorithm) => {
+                        repl.set_algorithm(algorithm);
+                        println!("switched to {name}");
+                    }
+                    None => println!("unknown algorithm: {name}"),
+                }
+            }
+            Some("hint") => println!("{}", repl.hint()),
+            Some("quit") | Some("exit") => break,
+            Some(other) => println!("unknown command: {other}"),
+            None => {}
+        }
+    }
+}
@@ -0,0 +1,142 @@
+//! Interactive REPL state, behind the `cli` feature. The binary in `src/bin/repl.rs`
+//! is a thin line-reading loop around this module.
+
+use std::collections::HashMap;
+
+use rand::seq::IteratorRandom;
+
+use crate::algorithms::constraints::ConstraintGuesser;
+use crate::algorithms::frequency::Frequency;
+use crate::algorithms::niave::Niave;
+use crate::{Correctness, Guess, Guesser, Wordle};
+
+/// Which solver `hint` should consult.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Algorithm {
+    Niave,
+    Constraints,
+    Frequency,
+}
+
+impl Algorithm {
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "niave" => Some(Self::Niave),
+            "constraints" => Some(Self::Constraints),
+            "frequency" => Some(Self::Frequency),
+            _ => None,
+        }
+    }
+}
+
+/// Drives a single live game: scores guesses against a hidden or chosen answer, and can
+/// ask the selected [`Algorithm`] for a hint from the still-possible candidates.
+pub struct Repl {
+    wordle: Wordle,
+    frequencies: HashMap<&'static str, usize>,
+    answer: Option<&'static str>,
+    history: Vec<Guess>,
+    algorithm: Algorithm,
+}
+
+impl Repl {
+    pub fn new() -> Self {
+        let wordle = Wordle::new();
+        let frequencies = wordle.frequencies().clone();
+        Self {
+            wordle,
+            frequencies,
+            answer: None,
+            history: Vec::new(),
+            algorithm: Algorithm::Frequency,
+        }
+    }
+
+    /// Start a new game. `answer`, if given, must be a dictionary word; otherwise an
+    /// arbitrary dictionary word is picked as the hidden answer.
+    pub fn new_game(&mut self, answer: Option<&str>) -> Result<(), String> {
+        let answer = match answer {
+            Some(word) => *self
+                .frequencies
+                .keys()
+                .find(|&&dict_word| dict_word == word)
+                .ok_or_else(|| format!("{word} is not in the dictionary"))?,
+            None => *self
+                .frequencies
+                .keys()
+                .choose(&mut rand::thread_rng())
+                .expect("dictionary is never empty"),
+        };
+        self.answer = Some(answer);
+        self.history.clear();
+        Ok(())
+    }
+
+    /// Score `word` against the current answer and record it in the history.
+    pub fn guess(&mut self, word: &str) -> Result<&Guess, String> {
+        let answer = self
+            .answer
+            .ok_or_else(|| "no game in progress; start one with `new`".to_string())?;
+        if !self.frequencies.contains_key(word) {
+            return Err(format!("{word} is not in the dictionary"));
+        }
+        let mask = Correctness::compute(answer, word);
+        self.history.push(Guess {
+            word: word.to_string(),
+            mask,
+        });
+        Ok(self.history.last().expect("just pushed"))
+    }
+
+    /// Undo the last `n` guesses by re-deriving state from the trimmed history.
+    pub fn undo(&mut self, n: usize) {
+        let keep = self.history.len().saturating_sub(n);
+        self.history.truncate(keep);
+    }
+
+    pub fn set_algorithm(&mut self, algorithm: Algorithm) {
+        self.algorithm = algorithm;
+    }
+
+    /// Ask the selected algorithm for its next guess, given the current history.
+    pub fn hint(&self) -> String {
+        match self.algorithm {
+            // Unlike `ConstraintGuesser`/`Frequency`, `Niave::guess` only ever looks at
+            // the most recent guess - it's meant to be called once per round on the
+            // same long-lived instance, the way `Wordle::play` drives it, accumulating
+            // each round's filter into `remaining`. Replay the whole history into a
+            // fresh instance round by round so a one-shot hint sees every guess, not
+            // just the last one.
+            Algorithm::Niave => {
+                let mut niave = Niave::new();
+                let mut hint = niave.guess(&[]);
+                for i in 1..=self.history.len() {
+                    hint = niave.guess(&self.history[..i]);
+                }
+                hint
+            }
+            Algorithm::Constraints => ConstraintGuesser::new().guess(&self.history),
+            Algorithm::Frequency => Frequency::new(&self.frequencies).guess(&self.history),
+        }
+    }
+
+    pub fn history(&self) -> &[Guess] {
+        &self.history
+    }
+
+    pub fn won(&self) -> bool {
+        self.history
+            .last()
+            .is_some_and(|guess| guess.mask.iter().all(|c| *c == Correctness::Correct))
+    }
+
+    pub fn wordle(&self) -> &Wordle {
+        &self.wordle
+    }
+}
+
+impl Default for Repl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,22 +1,55 @@
-use std::collections::HashSet;
+use std::collections::HashMap;
 
 pub mod algorithms;
+pub mod bench;
+#[cfg(feature = "cli")]
+pub mod cli;
+pub mod render;
 
 const MAX_GUESSES: usize = 32;
 const DICTIONARY: &str = include_str!("../dictionary.txt");
 
-pub struct Wordle {
-    dictionary: HashSet<&'static str>,
+/// Parse a `word count\nword count\n...` dictionary listing (whitespace-separated, one
+/// count per word) into a lookup from word to relative frequency.
+pub(crate) fn parse_dictionary(text: &'static str) -> HashMap<&'static str, usize> {
+    let mut tokens = text.split_whitespace();
+    let mut dictionary = HashMap::new();
+    while let (Some(word), Some(count)) = (tokens.next(), tokens.next()) {
+        dictionary.insert(
+            word,
+            count.parse().expect("dictionary count is a number"),
+        );
+    }
+    dictionary
+}
+
+pub struct Wordle<const N: usize = 5> {
+    /// Word -> relative frequency, as parsed from `dictionary.txt`.
+    frequencies: HashMap<&'static str, usize>,
 }
 
-impl Wordle {
+impl<const N: usize> Wordle<N> {
     pub fn new() -> Self {
-        Self {
-            dictionary: HashSet::from_iter(DICTIONARY.split_whitespace().step_by(2)),
-        }
+        let frequencies = parse_dictionary(DICTIONARY);
+        assert!(
+            frequencies.keys().all(|word| word.len() == N),
+            "dictionary.txt contains a word that isn't {N} letters long"
+        );
+        Self { frequencies }
     }
 
-    pub fn play<G: Guesser>(&self, answer: &'static str, mut guesser: G) -> Option<usize> {
+    /// The relative frequency of `word` in `dictionary.txt`, if it's a valid word.
+    pub fn frequency(&self, word: &str) -> Option<usize> {
+        self.frequencies.get(word).copied()
+    }
+
+    /// The full word -> relative frequency table, for guessers that want to bias their
+    /// picks toward more common words.
+    pub fn frequencies(&self) -> &HashMap<&'static str, usize> {
+        &self.frequencies
+    }
+
+    pub fn play<G: Guesser<N>>(&self, answer: &'static str, mut guesser: G) -> Option<usize> {
         // play six rounds invoking guesser each round
         let mut history = Vec::new();
         for i in 1..=MAX_GUESSES {
@@ -24,10 +57,10 @@ impl Wordle {
             if guess == answer {
                 return Some(i);
             }
-            assert!(self.dictionary.contains(&*guess));
+            assert!(self.frequencies.contains_key(&*guess));
             let correctness = Correctness::compute(answer, &guess);
-            history.push(Guess { 
-                word: guess, 
+            history.push(Guess {
+                word: guess,
                 mask: correctness,
             });
         }
@@ -35,6 +68,12 @@ impl Wordle {
     }
 }
 
+impl<const N: usize> Default for Wordle<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Correctness {
@@ -47,11 +86,11 @@ pub enum Correctness {
 }
 
 impl Correctness {
-    fn compute(answer: &str, guess: &str) -> [Self; 5] {
-        assert_eq!(answer.len(), 5);
-        assert_eq!(guess.len(), 5);
-        let mut c = [Correctness::Wrong; 5];
-        let mut used = [false; 5];
+    fn compute<const N: usize>(answer: &str, guess: &str) -> [Self; N] {
+        assert_eq!(answer.len(), N);
+        assert_eq!(guess.len(), N);
+        let mut c = [Correctness::Wrong; N];
+        let mut used = [false; N];
         // Evaluate correctness
         for (i, (a, g)) in answer.chars().zip(guess.chars()).enumerate() {
             if a == g {
@@ -73,13 +112,13 @@ impl Correctness {
     }
 }
 
-pub struct Guess {
+pub struct Guess<const N: usize = 5> {
     pub word: String,
-    pub mask: [Correctness; 5],
+    pub mask: [Correctness; N],
 }
 
-pub trait Guesser {
-    fn guess(&mut self, history: &[Guess]) -> String;
+pub trait Guesser<const N: usize = 5> {
+    fn guess(&mut self, history: &[Guess<N>]) -> String;
 }
 
 #[cfg(test)]
@@ -190,7 +229,7 @@ mod tests {
                 $(mask!($c)),+
             ]};
         }
-        
+
         #[test]
         fn all_green() {
             assert_eq!(
@@ -215,7 +254,7 @@ mod tests {
         #[test]
         fn repeat_green() {
             assert_eq!(
-                Correctness::compute("aabbb", "aaccc"), mask![C C W W W] 
+                Correctness::compute("aabbb", "aaccc"), mask![C C W W W]
             );
         }
 
@@ -240,4 +279,4 @@ mod tests {
             );
         }
     }
-}
\ No newline at end of file
+}
@@ -3,10 +3,11 @@ use za_wardle::Wordle;
 const GAMES: &str = include_str!("../answers.txt");
 
 fn main() {
-    for answer in GAMES.split_whitespace() {
-        let guesser = za_wardle::algorithms::niave::Niave::new();
-        let wordle = Wordle::new();
-        wordle.play(answer, guesser);
-    }
-    println!("hello, world!");
+    let answers: Vec<&str> = GAMES.split_whitespace().collect();
+    let wordle = Wordle::<5>::new();
+    let frequencies = wordle.frequencies();
+    let report = wordle.benchmark(&answers, || {
+        za_wardle::algorithms::frequency::Frequency::new(frequencies)
+    });
+    println!("{report}");
 }
@@ -0,0 +1,70 @@
+use std::fmt;
+use std::io::IsTerminal;
+
+use colored::Colorize;
+
+use crate::{Correctness, Guess};
+
+/// Render a `Correctness` mask as colorized terminal output, falling back to plain text
+/// when stdout isn't a TTY.
+pub trait ColorizedMask {
+    fn colorized(&self) -> String;
+}
+
+impl<const N: usize> ColorizedMask for [Correctness; N] {
+    fn colorized(&self) -> String {
+        let colorized = std::io::stdout().is_terminal();
+        self.iter().map(|&c| render_block(c, colorized)).collect()
+    }
+}
+
+impl<const N: usize> Guess<N> {
+    /// Render this guess's letters against their mask as colorized terminal output,
+    /// falling back to plain `LETTER(STATE)` text when stdout isn't a TTY.
+    pub fn colorized(&self) -> String {
+        let colorized = std::io::stdout().is_terminal();
+        self.word
+            .chars()
+            .zip(self.mask.iter())
+            .map(|(letter, &c)| render_letter(letter, c, colorized))
+            .collect()
+    }
+}
+
+impl<const N: usize> fmt::Display for Guess<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.colorized())
+    }
+}
+
+fn render_block(correctness: Correctness, colorized: bool) -> String {
+    if !colorized {
+        return match correctness {
+            Correctness::Correct => "C".to_string(),
+            Correctness::Misplaced => "M".to_string(),
+            Correctness::Wrong => "W".to_string(),
+        };
+    }
+    match correctness {
+        Correctness::Correct => "  ".on_green().to_string(),
+        Correctness::Misplaced => "  ".on_yellow().to_string(),
+        Correctness::Wrong => "  ".on_bright_black().to_string(),
+    }
+}
+
+fn render_letter(letter: char, correctness: Correctness, colorized: bool) -> String {
+    if !colorized {
+        let state = match correctness {
+            Correctness::Correct => 'C',
+            Correctness::Misplaced => 'M',
+            Correctness::Wrong => 'W',
+        };
+        return format!("{letter}({state})");
+    }
+    let letter = letter.to_ascii_uppercase().to_string();
+    match correctness {
+        Correctness::Correct => letter.on_green().black().to_string(),
+        Correctness::Misplaced => letter.on_yellow().black().to_string(),
+        Correctness::Wrong => letter.on_bright_black().white().to_string(),
+    }
+}